@@ -0,0 +1,147 @@
+use std::sync::OnceLock;
+
+use winapi::{
+    shared::{minwindef::WPARAM, windef::HWND},
+    um::winuser::{RegisterWindowMessageA, SendNotifyMessageA, HWND_BROADCAST},
+};
+
+const BROADCAST_MSG_NAME: &str = "IRSDK_BROADCASTMSG\0";
+
+/// A command sent to the simulator via the `IRSDK_BROADCASTMSG` window
+/// message. Each variant mirrors one of the documented `irsdk_BroadcastMsg`
+/// ids, carrying its arguments in the order the SDK expects them packed into
+/// `wParam`/`lParam`.
+#[derive(Debug, Clone, Copy)]
+pub enum Command {
+    CameraSwitchPos { position: i16, group: i16, camera: i16 },
+    CameraSwitchNum { car_number: i16, group: i16, camera: i16 },
+    CameraState(i16),
+    ReplaySetPlaySpeed { speed: i16, slow_motion: bool },
+    ReplaySetPlayPosition { pos_mode: i16, frame_num: i32 },
+    ReplaySearch(i16),
+    ReplaySetState(i16),
+    ReloadTextures { mode: i16, car_idx: i16 },
+    ChatCommand { mode: i16, macro_num: i16 },
+    PitCommand { command: i16, var: i16 },
+    TelemCommand(i16),
+    FFBCommand { command: i16, value: f32 },
+    ReplaySearchSessionTime { session_num: i16, session_time_ms: i32 },
+    VideoCapture(i16),
+}
+
+fn make_long(low: u16, high: u16) -> u32 {
+    (low as u32) | ((high as u32) << 16)
+}
+
+impl Command {
+    fn msg_id(&self) -> u16 {
+        match self {
+            Command::CameraSwitchPos { .. } => 0,
+            Command::CameraSwitchNum { .. } => 1,
+            Command::CameraState(_) => 2,
+            Command::ReplaySetPlaySpeed { .. } => 3,
+            Command::ReplaySetPlayPosition { .. } => 4,
+            Command::ReplaySearch(_) => 5,
+            Command::ReplaySetState(_) => 6,
+            Command::ReloadTextures { .. } => 7,
+            Command::ChatCommand { .. } => 8,
+            Command::PitCommand { .. } => 9,
+            Command::TelemCommand(_) => 10,
+            Command::FFBCommand { .. } => 11,
+            Command::ReplaySearchSessionTime { .. } => 12,
+            Command::VideoCapture(_) => 13,
+        }
+    }
+
+    /// Packs this command into the `(wParam, lParam)` pair `SendNotifyMessageA`
+    /// expects: the message id and first argument share `wParam`, the
+    /// remaining arguments share `lParam`.
+    fn pack(&self) -> (u32, i32) {
+        let (arg1, low2, high2) = match *self {
+            Command::CameraSwitchPos { position, group, camera } => (position, group, camera),
+            Command::CameraSwitchNum { car_number, group, camera } => (car_number, group, camera),
+            Command::CameraState(state) => (state, 0, 0),
+            Command::ReplaySetPlaySpeed { speed, slow_motion } => (speed, slow_motion as i16, 0),
+            Command::ReplaySearch(mode) => (mode, 0, 0),
+            Command::ReplaySetState(state) => (state, 0, 0),
+            Command::ReloadTextures { mode, car_idx } => (mode, car_idx, 0),
+            Command::ChatCommand { mode, macro_num } => (mode, macro_num, 0),
+            Command::PitCommand { command, var } => (command, var, 0),
+            Command::TelemCommand(state) => (state, 0, 0),
+            Command::VideoCapture(mode) => (mode, 0, 0),
+            Command::ReplaySetPlayPosition { pos_mode, frame_num } => {
+                return (
+                    make_long(self.msg_id(), pos_mode as u16),
+                    frame_num,
+                )
+            }
+            Command::FFBCommand { command, value } => {
+                // The wire format is Q16.16 fixed-point, not the raw IEEE-754 bits.
+                return (
+                    make_long(self.msg_id(), command as u16),
+                    (value * 65536.0) as i32,
+                )
+            }
+            Command::ReplaySearchSessionTime {
+                session_num,
+                session_time_ms,
+            } => {
+                return (
+                    make_long(self.msg_id(), session_num as u16),
+                    session_time_ms,
+                )
+            }
+        };
+
+        let wparam = make_long(self.msg_id(), arg1 as u16);
+        let lparam = make_long(low2 as u16, high2 as u16) as i32;
+        (wparam, lparam)
+    }
+}
+
+fn registered_message() -> u32 {
+    static MSG: OnceLock<u32> = OnceLock::new();
+    *MSG.get_or_init(|| unsafe { RegisterWindowMessageA(BROADCAST_MSG_NAME.as_ptr() as *const i8) })
+}
+
+/// Broadcasts a [`Command`] to every top-level window, matching the protocol
+/// the simulator itself listens for.
+pub fn send(command: Command) {
+    let msg = registered_message();
+    let (wparam, lparam) = command.pack();
+
+    unsafe {
+        SendNotifyMessageA(HWND_BROADCAST as HWND, msg, wparam as WPARAM, lparam as isize);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn video_capture_uses_the_id_after_replay_search_session_time() {
+        let (wparam, _) = Command::VideoCapture(0).pack();
+        assert_eq!(wparam & 0xFFFF, 13);
+    }
+
+    #[test]
+    fn chat_command_packs_macro_num_into_lparam() {
+        let (_, lparam) = Command::ChatCommand {
+            mode: 0,
+            macro_num: 4,
+        }
+        .pack();
+        assert_eq!(lparam & 0xFFFF, 4);
+    }
+
+    #[test]
+    fn ffb_command_packs_value_as_q16_16_fixed_point() {
+        let (_, lparam) = Command::FFBCommand {
+            command: 0,
+            value: 1.5,
+        }
+        .pack();
+        assert_eq!(lparam, (1.5_f32 * 65536.0) as i32);
+    }
+}
@@ -0,0 +1,214 @@
+use std::{mem::MaybeUninit, time::Duration};
+
+use winapi::{
+    shared::minwindef::{FALSE, LPCVOID},
+    um::{
+        errhandlingapi::GetLastError,
+        memoryapi::{
+            MapViewOfFile, OpenFileMappingW, UnmapViewOfFile, VirtualQuery, FILE_MAP_COPY,
+            FILE_MAP_READ,
+        },
+        synchapi::{OpenEventW, WaitForSingleObject},
+        winbase::{INFINITE, WAIT_FAILED, WAIT_TIMEOUT},
+        winnt::{MEMORY_BASIC_INFORMATION, SYNCHRONIZE},
+    },
+};
+
+use crate::{
+    handle::{AsHandle, BorrowedHandle, OwnedHandle},
+    header::SdkHeader,
+    session_info,
+    session_info::SessionInfo,
+    Error,
+};
+
+pub const TELEMETRY_PATH: &str = r"Local\IRSDKMemMapFileName";
+pub const DATA_EVENT_NAME: &str = r"Local\IRSDKDataValidEvent";
+
+pub struct Connection {
+    mapping: OwnedHandle,
+    event: OwnedHandle,
+    session_info_cache: Option<(i32, SessionInfo)>,
+}
+
+impl Connection {
+    pub fn open() -> Result<Self, Error> {
+        let telemetry_path = TELEMETRY_PATH
+            .encode_utf16()
+            .chain(Some(0))
+            .collect::<Vec<_>>();
+
+        let mapping = unsafe { OpenFileMappingW(FILE_MAP_READ, FALSE, telemetry_path.as_ptr()) };
+        let mapping = unsafe { OwnedHandle::new(mapping) }.ok_or(Error::TelemetryNotPresent)?;
+
+        let event_name = DATA_EVENT_NAME
+            .encode_utf16()
+            .chain(Some(0))
+            .collect::<Vec<_>>();
+
+        let event = unsafe { OpenEventW(SYNCHRONIZE, FALSE, event_name.as_ptr()) };
+        let event = unsafe { OwnedHandle::new(event) }.ok_or(Error::EventNotPresent)?;
+
+        Ok(Connection {
+            mapping,
+            event,
+            session_info_cache: None,
+        })
+    }
+
+    /// Waits for the sim to signal that a fresh sample is ready. `timeout`
+    /// of `None` blocks indefinitely; otherwise returns `Ok(false)` if the
+    /// timeout elapsed without the event firing.
+    pub fn wait_for_event(&self, timeout: Option<Duration>) -> Result<bool, Error> {
+        wait_on(self.event.as_handle(), timeout)
+    }
+
+    /// Polls for the event without blocking.
+    pub fn try_wait(&self) -> Result<bool, Error> {
+        self.wait_for_event(Some(Duration::ZERO))
+    }
+
+    /// Borrows the data-valid event handle without transferring ownership,
+    /// for embedders that want to wait on it alongside their own handles
+    /// via `WaitForMultipleObjects`.
+    pub fn event_handle(&self) -> BorrowedHandle<'_> {
+        self.event.as_handle()
+    }
+
+    /// Returns the parsed session-info document, re-parsing the YAML blob
+    /// only when `header.session_info_update` has changed since the last
+    /// call.
+    pub fn session_info(
+        &mut self,
+        header: &SdkHeader,
+        view: &View,
+    ) -> Result<&SessionInfo, Error> {
+        if !cache_is_fresh(&self.session_info_cache, header.session_info_update) {
+            let start = header.session_info_offset as usize;
+            let len = header.session_info_len as usize;
+            let bytes = start
+                .checked_add(len)
+                .and_then(|end| view.as_bytes().get(start..end))
+                .ok_or(Error::BufferTooSmall)?;
+            let info = session_info::parse(bytes)?;
+            self.session_info_cache = Some((header.session_info_update, info));
+        }
+
+        Ok(&self.session_info_cache.as_ref().unwrap().1)
+    }
+
+    pub(crate) fn mapping(&self) -> BorrowedHandle<'_> {
+        self.mapping.as_handle()
+    }
+}
+
+/// Whether the cached session info is still valid for `update`, i.e. it was
+/// last parsed from the same `session_info_update` tick the sim is
+/// currently reporting.
+fn cache_is_fresh(cache: &Option<(i32, SessionInfo)>, update: i32) -> bool {
+    matches!(cache, Some((cached_update, _)) if *cached_update == update)
+}
+
+/// Waits on `handle` becoming signaled. `timeout` of `None` blocks
+/// indefinitely; otherwise returns `Ok(false)` if the timeout elapsed
+/// without the handle being signaled.
+fn wait_on(handle: BorrowedHandle<'_>, timeout: Option<Duration>) -> Result<bool, Error> {
+    let millis = match timeout {
+        None => INFINITE,
+        Some(duration) => duration.as_millis().min(u128::from(INFINITE - 1)) as u32,
+    };
+
+    let res = unsafe { WaitForSingleObject(handle.as_raw(), millis) };
+    match res {
+        WAIT_FAILED => {
+            let errno = unsafe { GetLastError() };
+            Err(Error::WaitFailed(errno))
+        }
+        WAIT_TIMEOUT => Ok(false),
+        _ => Ok(true),
+    }
+}
+
+pub struct View {
+    view: LPCVOID,
+    len: usize,
+}
+
+impl View {
+    pub fn create(conn: &Connection) -> Result<Self, Error> {
+        let view = unsafe {
+            MapViewOfFile(conn.mapping().as_raw(), FILE_MAP_READ | FILE_MAP_COPY, 0, 0, 0)
+        };
+        if view.is_null() {
+            return Err(Error::ViewCreationFailed);
+        }
+
+        let mut info = MaybeUninit::<MEMORY_BASIC_INFORMATION>::uninit();
+        let written = unsafe {
+            VirtualQuery(
+                view,
+                info.as_mut_ptr(),
+                std::mem::size_of::<MEMORY_BASIC_INFORMATION>(),
+            )
+        };
+        if written == 0 {
+            unsafe { UnmapViewOfFile(view) };
+            return Err(Error::ViewCreationFailed);
+        }
+        let len = unsafe { info.assume_init() }.RegionSize;
+
+        Ok(Self { view, len })
+    }
+
+    /// The mapped view as a byte slice, bounded by the region size the OS
+    /// reports for the mapping, so callers can never read past it.
+    pub fn as_bytes(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self.view as *const u8, self.len) }
+    }
+}
+
+impl Drop for View {
+    fn drop(&mut self) {
+        unsafe { UnmapViewOfFile(self.view) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session_info::{DriverInfo, SessionList, WeekendInfo};
+
+    fn sample_session_info() -> SessionInfo {
+        SessionInfo {
+            weekend_info: WeekendInfo {
+                track_name: "silverstone".into(),
+                track_display_name: "Silverstone".into(),
+                track_weather_type: None,
+                track_skies: None,
+                track_air_temp: None,
+            },
+            session_info: SessionList { sessions: vec![] },
+            driver_info: DriverInfo {
+                driver_car_idx: 0,
+                drivers: vec![],
+            },
+        }
+    }
+
+    #[test]
+    fn cache_is_not_fresh_when_empty() {
+        assert!(!cache_is_fresh(&None, 1));
+    }
+
+    #[test]
+    fn cache_is_fresh_when_the_update_tick_matches() {
+        let cache = Some((5, sample_session_info()));
+        assert!(cache_is_fresh(&cache, 5));
+    }
+
+    #[test]
+    fn cache_is_not_fresh_when_the_update_tick_has_changed() {
+        let cache = Some((5, sample_session_info()));
+        assert!(!cache_is_fresh(&cache, 6));
+    }
+}
@@ -0,0 +1,59 @@
+//! RAII ownership for Windows `HANDLE`s, mirroring std's I/O-safety model:
+//! an [`OwnedHandle`] closes on drop, a [`BorrowedHandle`] merely observes
+//! it, and [`AsHandle`] lets callers ask for a borrow without taking
+//! ownership.
+
+use std::marker::PhantomData;
+
+use winapi::um::{handleapi::CloseHandle, winnt::HANDLE};
+
+pub struct OwnedHandle(HANDLE);
+
+impl OwnedHandle {
+    /// Takes ownership of `handle`, returning `None` if it is null rather
+    /// than wrapping an invalid handle that would later be misused.
+    ///
+    /// # Safety
+    /// `handle`, if non-null, must be a valid, open handle not owned by
+    /// anything else.
+    pub unsafe fn new(handle: HANDLE) -> Option<Self> {
+        if handle.is_null() {
+            None
+        } else {
+            Some(Self(handle))
+        }
+    }
+}
+
+impl AsHandle for OwnedHandle {
+    fn as_handle(&self) -> BorrowedHandle<'_> {
+        BorrowedHandle {
+            handle: self.0,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl Drop for OwnedHandle {
+    fn drop(&mut self) {
+        unsafe {
+            CloseHandle(self.0);
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct BorrowedHandle<'a> {
+    handle: HANDLE,
+    _marker: PhantomData<&'a OwnedHandle>,
+}
+
+impl BorrowedHandle<'_> {
+    pub fn as_raw(&self) -> HANDLE {
+        self.handle
+    }
+}
+
+pub trait AsHandle {
+    fn as_handle(&self) -> BorrowedHandle<'_>;
+}
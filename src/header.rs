@@ -0,0 +1,196 @@
+//! `#[repr(C)]` mirrors of the SDK's wire layout, parsed out of a mapped
+//! view via [`zerocopy`] so that offsets and lengths coming from another
+//! process can never drive an out-of-bounds read.
+
+use zerocopy::{FromBytes, Immutable, KnownLayout, Ref};
+
+use crate::Error;
+
+pub const MAX_BUFS: usize = 4;
+pub const MAX_STRING: usize = 32;
+pub const MAX_DESC: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VarType {
+    Char,
+    Bool,
+    Int,
+    BitField,
+    Float,
+    Double,
+}
+
+impl VarType {
+    pub fn byte_len(&self) -> usize {
+        match self {
+            VarType::Char | VarType::Bool => 1,
+            VarType::Int | VarType::BitField | VarType::Float => 4,
+            VarType::Double => 8,
+        }
+    }
+}
+
+impl TryFrom<i32> for VarType {
+    type Error = Error;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(VarType::Char),
+            1 => Ok(VarType::Bool),
+            2 => Ok(VarType::Int),
+            3 => Ok(VarType::BitField),
+            4 => Ok(VarType::Float),
+            5 => Ok(VarType::Double),
+            x => Err(Error::UnknownVarType(x)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, FromBytes, Immutable, KnownLayout)]
+#[repr(C)]
+pub struct VarHeader {
+    var_type: i32,
+    pub offset: i32,
+    pub count: i32,
+    count_as_time: i32,
+    pub name: [u8; MAX_STRING],
+    pub desc: [u8; MAX_DESC],
+    pub unit: [u8; MAX_STRING],
+}
+
+impl VarHeader {
+    /// The raw `type` field is only validated here, on demand, rather than
+    /// while parsing the header out of the mapped view.
+    pub fn var_type(&self) -> Result<VarType, Error> {
+        VarType::try_from(self.var_type)
+    }
+
+    pub fn count_as_time(&self) -> bool {
+        self.count_as_time != 0
+    }
+
+    /// The variable's name, decoded up to its NUL terminator.
+    pub fn name(&self) -> String {
+        let end = self
+            .name
+            .iter()
+            .position(|&b| b == 0)
+            .unwrap_or(self.name.len());
+        String::from_utf8_lossy(&self.name[..end]).into_owned()
+    }
+}
+
+#[derive(Debug, Clone, Copy, FromBytes, Immutable, KnownLayout)]
+#[repr(C)]
+pub struct VarBuf {
+    pub tick_count: i32,
+    pub buf_offset: i32,
+    _pad: [i32; 2],
+}
+
+#[derive(Debug, Clone, Copy, FromBytes, Immutable, KnownLayout)]
+#[repr(C)]
+pub struct SdkHeader {
+    pub version: i32,
+    pub status: i32,
+    pub tick_rate: i32,
+    pub session_info_update: i32,
+    pub session_info_len: i32,
+    pub session_info_offset: i32,
+    pub num_vars: i32,
+    pub var_header_offset: i32,
+    pub num_buf: i32,
+    pub buf_len: i32,
+    _pad: [i32; 2],
+    pub var_bufs: [VarBuf; MAX_BUFS],
+}
+
+impl SdkHeader {
+    /// Parses the header out of the front of `bytes`, bounds-checked
+    /// against the mapped view's actual size.
+    pub fn parse(bytes: &[u8]) -> Result<&SdkHeader, Error> {
+        let (header, _rest) =
+            Ref::<_, SdkHeader>::from_prefix(bytes).map_err(|_| Error::BufferTooSmall)?;
+        Ok(Ref::into_ref(header))
+    }
+
+    /// Parses `num_vars` [`VarHeader`]s starting at `var_header_offset`,
+    /// bounds-checked against `bytes`.
+    pub fn var_headers<'a>(&self, bytes: &'a [u8]) -> Result<&'a [VarHeader], Error> {
+        let region = bytes
+            .get(self.var_header_offset as usize..)
+            .ok_or(Error::BufferTooSmall)?;
+        let (headers, _rest) =
+            Ref::<_, [VarHeader]>::from_prefix_with_elems(region, self.num_vars as usize)
+                .map_err(|_| Error::BufferTooSmall)?;
+        Ok(Ref::into_ref(headers))
+    }
+
+    pub fn newest_var_buf(&self) -> VarBuf {
+        *self
+            .var_bufs
+            .iter()
+            .max_by_key(|buf| buf.tick_count)
+            .expect("var_bufs is never empty")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a valid `SdkHeader` byte layout with the given `num_vars` and
+    /// `var_header_offset`, but nothing beyond the header itself - so any
+    /// attempt to read var headers out of it must come from bounds-checking,
+    /// not from there actually being data there.
+    fn sdk_header_bytes(num_vars: i32, var_header_offset: i32) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for field in [0, 0, 60, 0, 0, 0, num_vars, var_header_offset, 1, 16] {
+            bytes.extend_from_slice(&(field as i32).to_ne_bytes());
+        }
+        bytes.extend_from_slice(&[0u8; 8]);
+        for _ in 0..MAX_BUFS {
+            bytes.extend_from_slice(&0i32.to_ne_bytes());
+            bytes.extend_from_slice(&0i32.to_ne_bytes());
+            bytes.extend_from_slice(&[0u8; 8]);
+        }
+        bytes
+    }
+
+    #[test]
+    fn parse_rejects_a_truncated_buffer() {
+        let bytes = vec![0u8; 10];
+        assert!(matches!(SdkHeader::parse(&bytes), Err(Error::BufferTooSmall)));
+    }
+
+    #[test]
+    fn parse_accepts_a_full_size_buffer() {
+        let bytes = sdk_header_bytes(0, 0);
+        assert!(SdkHeader::parse(&bytes).is_ok());
+    }
+
+    #[test]
+    fn var_headers_rejects_a_corrupted_var_header_offset() {
+        let bytes = sdk_header_bytes(1, 1_000_000);
+        let header = SdkHeader::parse(&bytes).unwrap();
+        assert!(matches!(
+            header.var_headers(&bytes),
+            Err(Error::BufferTooSmall)
+        ));
+    }
+
+    #[test]
+    fn var_headers_rejects_a_corrupted_num_vars() {
+        let offset = bytes_len();
+        let bytes = sdk_header_bytes(1000, offset);
+        let header = SdkHeader::parse(&bytes).unwrap();
+        assert!(matches!(
+            header.var_headers(&bytes),
+            Err(Error::BufferTooSmall)
+        ));
+    }
+
+    fn bytes_len() -> i32 {
+        sdk_header_bytes(0, 0).len() as i32
+    }
+}
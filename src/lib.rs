@@ -1,4 +1,9 @@
-pub mod ptr;
+pub mod broadcast;
+pub mod connection;
+pub mod handle;
+pub mod header;
+pub mod sample;
+pub mod session_info;
 
 #[derive(Debug)]
 pub enum Error {
@@ -7,4 +12,7 @@ pub enum Error {
     ViewCreationFailed,
     UnknownVarType(i32),
     WaitFailed(u32),
+    SessionInfoParse(serde_yaml::Error),
+    TornRead,
+    BufferTooSmall,
 }
@@ -0,0 +1,320 @@
+//! Typed telemetry values read out of the newest, tear-free `VarBuf`.
+
+use std::collections::HashMap;
+
+use crate::{
+    connection::View,
+    header::{SdkHeader, VarHeader, VarType},
+    Error,
+};
+
+/// Bounded number of attempts to read a buffer before giving up, in case the
+/// sim keeps swapping buffers faster than we can copy one out.
+const MAX_RETRIES: usize = 4;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum VarValue {
+    Char(u8),
+    Bool(bool),
+    Int(i32),
+    BitField(u32),
+    Float(f32),
+    Double(f64),
+    CharArray(Vec<u8>),
+    BoolArray(Vec<bool>),
+    IntArray(Vec<i32>),
+    BitFieldArray(Vec<u32>),
+    FloatArray(Vec<f32>),
+    DoubleArray(Vec<f64>),
+}
+
+pub struct Sample {
+    values: HashMap<String, VarValue>,
+}
+
+/// Supplies the live mapped telemetry bytes. Implemented by [`View`] for
+/// production use; tests provide fakes that simulate the sim swapping
+/// buffers mid-read.
+pub trait Source {
+    fn bytes(&self) -> &[u8];
+}
+
+impl Source for View {
+    fn bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl Sample {
+    /// Reads every variable in `var_headers` out of the newest `VarBuf` in
+    /// `header`, following the SDK's anti-tearing protocol: the source
+    /// buffer's `tick_count` is recorded before copying and re-checked
+    /// afterwards. If the sim swapped buffers while we were copying, the
+    /// read is retried against the now-newest buffer, for at most
+    /// [`MAX_RETRIES`] attempts in all.
+    pub fn read(
+        source: &impl Source,
+        header: &SdkHeader,
+        var_headers: &[VarHeader],
+    ) -> Result<Self, Error> {
+        let mut buf = header.newest_var_buf();
+
+        for _ in 0..MAX_RETRIES {
+            let before = buf.tick_count;
+
+            let bytes = source.bytes();
+            let start = buf.buf_offset as usize;
+            let len = header.buf_len as usize;
+            let region = start
+                .checked_add(len)
+                .and_then(|end| bytes.get(start..end))
+                .ok_or(Error::BufferTooSmall)?;
+
+            let values = var_headers
+                .iter()
+                .map(|h| Ok((h.name(), read_value(region, h)?)))
+                .collect::<Result<_, Error>>()?;
+
+            let newest = SdkHeader::parse(source.bytes())?.newest_var_buf();
+            if newest.tick_count == before {
+                return Ok(Sample { values });
+            }
+
+            buf = newest;
+        }
+
+        Err(Error::TornRead)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&VarValue> {
+        self.values.get(name)
+    }
+}
+
+fn read_i32(bytes: &[u8], at: usize) -> i32 {
+    i32::from_ne_bytes(bytes[at..at + 4].try_into().unwrap())
+}
+
+fn read_u32(bytes: &[u8], at: usize) -> u32 {
+    u32::from_ne_bytes(bytes[at..at + 4].try_into().unwrap())
+}
+
+fn read_f32(bytes: &[u8], at: usize) -> f32 {
+    f32::from_ne_bytes(bytes[at..at + 4].try_into().unwrap())
+}
+
+fn read_f64(bytes: &[u8], at: usize) -> f64 {
+    f64::from_ne_bytes(bytes[at..at + 8].try_into().unwrap())
+}
+
+fn read_value(bytes: &[u8], header: &VarHeader) -> Result<VarValue, Error> {
+    let var_type = header.var_type()?;
+    let offset = header.offset as usize;
+    let count = header.count.max(1) as usize;
+    let elem_len = var_type.byte_len();
+
+    let region = elem_len
+        .checked_mul(count)
+        .and_then(|len| offset.checked_add(len))
+        .and_then(|end| bytes.get(offset..end))
+        .ok_or(Error::BufferTooSmall)?;
+
+    if count == 1 {
+        return Ok(match var_type {
+            VarType::Char => VarValue::Char(region[0]),
+            VarType::Bool => VarValue::Bool(region[0] != 0),
+            VarType::Int => VarValue::Int(read_i32(region, 0)),
+            VarType::BitField => VarValue::BitField(read_u32(region, 0)),
+            VarType::Float => VarValue::Float(read_f32(region, 0)),
+            VarType::Double => VarValue::Double(read_f64(region, 0)),
+        });
+    }
+
+    Ok(match var_type {
+        VarType::Char => VarValue::CharArray(region.to_vec()),
+        VarType::Bool => VarValue::BoolArray(region.iter().map(|&b| b != 0).collect()),
+        VarType::Int => {
+            VarValue::IntArray((0..count).map(|i| read_i32(region, i * elem_len)).collect())
+        }
+        VarType::BitField => VarValue::BitFieldArray(
+            (0..count)
+                .map(|i| read_u32(region, i * elem_len))
+                .collect(),
+        ),
+        VarType::Float => VarValue::FloatArray(
+            (0..count)
+                .map(|i| read_f32(region, i * elem_len))
+                .collect(),
+        ),
+        VarType::Double => VarValue::DoubleArray(
+            (0..count)
+                .map(|i| read_f64(region, i * elem_len))
+                .collect(),
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use zerocopy::Ref;
+
+    use super::*;
+    use crate::header::{SdkHeader, MAX_BUFS, MAX_DESC, MAX_STRING};
+
+    fn var_header_bytes(var_type: i32, offset: i32, count: i32) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for field in [var_type, offset, count, 0] {
+            bytes.extend_from_slice(&field.to_ne_bytes());
+        }
+        bytes.extend_from_slice(&[0u8; MAX_STRING]);
+        bytes.extend_from_slice(&[0u8; MAX_DESC]);
+        bytes.extend_from_slice(&[0u8; MAX_STRING]);
+        bytes
+    }
+
+    fn var_header(var_type: i32, offset: i32, count: i32) -> VarHeader {
+        let bytes = var_header_bytes(var_type, offset, count);
+        let (header, _rest) = Ref::<_, VarHeader>::from_prefix(&bytes).unwrap();
+        *Ref::into_ref(header)
+    }
+
+    #[test]
+    fn read_value_decodes_each_scalar_type() {
+        assert_eq!(
+            read_value(&[7], &var_header(0, 0, 1)).unwrap(),
+            VarValue::Char(7)
+        );
+        assert_eq!(
+            read_value(&[1], &var_header(1, 0, 1)).unwrap(),
+            VarValue::Bool(true)
+        );
+        assert_eq!(
+            read_value(&42i32.to_ne_bytes(), &var_header(2, 0, 1)).unwrap(),
+            VarValue::Int(42)
+        );
+        assert_eq!(
+            read_value(&0xABCDu32.to_ne_bytes(), &var_header(3, 0, 1)).unwrap(),
+            VarValue::BitField(0xABCD)
+        );
+        assert_eq!(
+            read_value(&1.5f32.to_ne_bytes(), &var_header(4, 0, 1)).unwrap(),
+            VarValue::Float(1.5)
+        );
+        assert_eq!(
+            read_value(&2.5f64.to_ne_bytes(), &var_header(5, 0, 1)).unwrap(),
+            VarValue::Double(2.5)
+        );
+    }
+
+    #[test]
+    fn read_value_decodes_each_array_type() {
+        let ints: Vec<u8> = [1i32, 2, 3].iter().flat_map(|v| v.to_ne_bytes()).collect();
+        assert_eq!(
+            read_value(&ints, &var_header(2, 0, 3)).unwrap(),
+            VarValue::IntArray(vec![1, 2, 3])
+        );
+
+        let floats: Vec<u8> = [1.0f32, 2.0].iter().flat_map(|v| v.to_ne_bytes()).collect();
+        assert_eq!(
+            read_value(&floats, &var_header(4, 0, 2)).unwrap(),
+            VarValue::FloatArray(vec![1.0, 2.0])
+        );
+
+        assert_eq!(
+            read_value(&[1, 0, 1], &var_header(1, 0, 3)).unwrap(),
+            VarValue::BoolArray(vec![true, false, true])
+        );
+    }
+
+    #[test]
+    fn read_value_rejects_an_out_of_bounds_region() {
+        let result = read_value(&[0u8; 2], &var_header(2, 0, 1));
+        assert!(matches!(result, Err(Error::BufferTooSmall)));
+    }
+
+    struct FakeSource {
+        frames: Vec<Vec<u8>>,
+        next: Cell<usize>,
+    }
+
+    impl Source for FakeSource {
+        fn bytes(&self) -> &[u8] {
+            let i = self.next.get();
+            self.next.set(i + 1);
+            &self.frames[i.min(self.frames.len() - 1)]
+        }
+    }
+
+    fn header_len() -> i32 {
+        sdk_header_bytes(4, [(0, 0); MAX_BUFS]).len() as i32
+    }
+
+    fn sdk_header_bytes(buf_len: i32, var_bufs: [(i32, i32); MAX_BUFS]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for field in [0, 0, 60, 0, 0, 0, 0, 0, MAX_BUFS as i32, buf_len] {
+            bytes.extend_from_slice(&field.to_ne_bytes());
+        }
+        bytes.extend_from_slice(&[0u8; 8]);
+        for (tick_count, buf_offset) in var_bufs {
+            bytes.extend_from_slice(&tick_count.to_ne_bytes());
+            bytes.extend_from_slice(&buf_offset.to_ne_bytes());
+            bytes.extend_from_slice(&[0u8; 8]);
+        }
+        bytes
+    }
+
+    /// A full `SdkHeader`-prefixed buffer with a single `i32` variable's
+    /// value appended right after it, at `offset`.
+    fn frame(tick_count: i32, offset: i32, value: i32) -> Vec<u8> {
+        let var_bufs = [(tick_count, offset), (i32::MIN, 0), (i32::MIN, 0), (i32::MIN, 0)];
+        let mut bytes = sdk_header_bytes(4, var_bufs);
+        bytes.extend_from_slice(&value.to_ne_bytes());
+        bytes
+    }
+
+    #[test]
+    fn read_retries_against_the_newest_buffer_when_tick_count_changes_mid_copy() {
+        let offset = header_len();
+
+        let header_bytes = frame(1, offset, 0);
+        let header = SdkHeader::parse(&header_bytes).unwrap();
+        let var_headers = vec![var_header(2, 0, 1)];
+
+        let source = FakeSource {
+            frames: vec![
+                frame(1, offset, 10), // iteration 1: copy (stale value)
+                frame(2, offset, 0),  // iteration 1: recheck sees a newer tick
+                frame(2, offset, 20), // iteration 2: copy (fresh value)
+                frame(2, offset, 0),  // iteration 2: recheck matches
+            ],
+            next: Cell::new(0),
+        };
+
+        let sample = Sample::read(&source, header, &var_headers).unwrap();
+        assert_eq!(sample.get(""), Some(&VarValue::Int(20)));
+    }
+
+    #[test]
+    fn read_gives_up_after_max_retries_and_returns_torn_read() {
+        let offset = header_len();
+
+        let header_bytes = frame(1, offset, 0);
+        let header = SdkHeader::parse(&header_bytes).unwrap();
+        let var_headers = vec![var_header(2, 0, 1)];
+
+        // Each call reports a strictly newer tick_count than the last, so
+        // the buffer never looks stable across a copy/recheck pair.
+        let frames = (1..=2 * MAX_RETRIES as i32 + 2)
+            .map(|tick| frame(tick, offset, 0))
+            .collect();
+        let source = FakeSource {
+            frames,
+            next: Cell::new(0),
+        };
+
+        let result = Sample::read(&source, header, &var_headers);
+        assert!(matches!(result, Err(Error::TornRead)));
+    }
+}
@@ -0,0 +1,132 @@
+//! Parsing for the YAML session-info blob the sim writes alongside telemetry.
+
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SessionInfo {
+    #[serde(rename = "WeekendInfo")]
+    pub weekend_info: WeekendInfo,
+    #[serde(rename = "SessionInfo")]
+    pub session_info: SessionList,
+    #[serde(rename = "DriverInfo")]
+    pub driver_info: DriverInfo,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WeekendInfo {
+    #[serde(rename = "TrackName")]
+    pub track_name: String,
+    #[serde(rename = "TrackDisplayName")]
+    pub track_display_name: String,
+    #[serde(rename = "TrackWeatherType")]
+    pub track_weather_type: Option<String>,
+    #[serde(rename = "TrackSkies")]
+    pub track_skies: Option<String>,
+    #[serde(rename = "TrackAirTemp")]
+    pub track_air_temp: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SessionList {
+    #[serde(rename = "Sessions")]
+    pub sessions: Vec<Session>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Session {
+    #[serde(rename = "SessionNum")]
+    pub session_num: i32,
+    #[serde(rename = "SessionType")]
+    pub session_type: String,
+    #[serde(rename = "SessionName")]
+    pub session_name: String,
+    #[serde(rename = "SessionLaps")]
+    pub session_laps: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DriverInfo {
+    #[serde(rename = "DriverCarIdx")]
+    pub driver_car_idx: i32,
+    #[serde(rename = "Drivers")]
+    pub drivers: Vec<Driver>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Driver {
+    #[serde(rename = "CarIdx")]
+    pub car_idx: i32,
+    #[serde(rename = "UserName")]
+    pub user_name: String,
+    #[serde(rename = "CarNumber")]
+    pub car_number: String,
+    #[serde(rename = "CarNumberRaw")]
+    pub car_number_raw: i32,
+    #[serde(rename = "CarClassID")]
+    pub car_class_id: i32,
+}
+
+/// Decodes `bytes` as Windows-1252 (the sim does not emit UTF-8) and parses
+/// the resulting text as the session-info YAML document.
+pub(crate) fn parse(bytes: &[u8]) -> Result<SessionInfo, crate::Error> {
+    let (text, _, _) = encoding_rs::WINDOWS_1252.decode(bytes);
+    serde_yaml::from_str(&text).map_err(crate::Error::SessionInfoParse)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YAML: &str = r#"
+WeekendInfo:
+  TrackName: silverstone
+  TrackDisplayName: Silverstone
+SessionInfo:
+  Sessions:
+  - SessionNum: 0
+    SessionType: Race
+    SessionName: RACE
+DriverInfo:
+  DriverCarIdx: 0
+  Drivers:
+  - CarIdx: 0
+    UserName: A Driver
+    CarNumber: '3'
+    CarNumberRaw: 3
+    CarClassID: 1
+"#;
+
+    #[test]
+    fn parse_decodes_windows_1252_bytes_that_are_not_valid_utf8() {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(b"WeekendInfo:\n  TrackName: x\n  TrackDisplayName: x\n");
+        bytes.extend_from_slice(b"SessionInfo:\n  Sessions: []\n");
+        bytes.extend_from_slice(b"DriverInfo:\n  DriverCarIdx: 0\n  Drivers:\n");
+        bytes.extend_from_slice(b"  - CarIdx: 0\n    UserName: \"Driver");
+        // 0x92 is RIGHT SINGLE QUOTATION MARK in windows-1252, but on its
+        // own it's not valid UTF-8.
+        bytes.push(0x92);
+        bytes.extend_from_slice(b"s Car\"\n    CarNumber: \"3\"\n    CarNumberRaw: 3\n");
+        bytes.extend_from_slice(b"    CarClassID: 1\n");
+
+        let info = parse(&bytes).unwrap();
+        assert_eq!(info.driver_info.drivers[0].user_name, "Driver\u{2019}s Car");
+    }
+
+    #[test]
+    fn parse_parses_the_documented_yaml_shape() {
+        let info = parse(YAML.as_bytes()).unwrap();
+
+        assert_eq!(info.weekend_info.track_name, "silverstone");
+        assert_eq!(info.weekend_info.track_display_name, "Silverstone");
+        assert!(info.weekend_info.track_skies.is_none());
+
+        assert_eq!(info.session_info.sessions.len(), 1);
+        assert_eq!(info.session_info.sessions[0].session_type, "Race");
+
+        assert_eq!(info.driver_info.driver_car_idx, 0);
+        assert_eq!(info.driver_info.drivers.len(), 1);
+        assert_eq!(info.driver_info.drivers[0].car_number, "3");
+        assert_eq!(info.driver_info.drivers[0].car_class_id, 1);
+    }
+}